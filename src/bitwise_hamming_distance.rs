@@ -1,22 +1,44 @@
 //! Bitwise hamming distance calculation
 
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
 /// A trait for calculating the bitwise hamming distance
 pub trait BitwiseHammingDistancable<RHS = Self> {
     /// The output type of the hamming distance
     type Output;
+    /// The output type of the bounded hamming distance
+    type BoundedOutput;
     fn bitwise_hamming_distance(self, other: RHS) -> Self::Output;
+    /// Calculate the hamming distance, bailing out early once it exceeds `k`.
+    ///
+    /// Returns `None` as soon as the running distance exceeds `k`, without
+    /// finishing the comparison, rather than `Some` of the full distance.
+    fn bitwise_hamming_distance_bounded(self, other: RHS, k: u32) -> Self::BoundedOutput;
 }
 
 impl<'a, 'b> BitwiseHammingDistancable<&'a u8> for &'b u8 {
     type Output = u32;
+    type BoundedOutput = Option<u32>;
     /// Calculate the number of different bits between two `u8` bytes.
     fn bitwise_hamming_distance(self, other: &u8) -> u32 {
         return (self ^ other).count_ones();
     }
+
+    /// Calculate the number of different bits between two `u8` bytes, bailing
+    /// out if it exceeds `k`.
+    fn bitwise_hamming_distance_bounded(self, other: &u8, k: u32) -> Option<u32> {
+        let distance = (self ^ other).count_ones();
+        if distance > k {
+            return None;
+        }
+        return Some(distance);
+    }
 }
 
 impl<'a, 'b> BitwiseHammingDistancable<&'a Vec<u8>> for &'b Vec<u8> {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the number of different bits between two vectors of `u8` bytes.
     fn bitwise_hamming_distance(self, other: &Vec<u8>) -> Result<u32, &'static str> {
         if self.len() != other.len() {
@@ -28,21 +50,178 @@ impl<'a, 'b> BitwiseHammingDistancable<&'a Vec<u8>> for &'b Vec<u8> {
         }
         return Ok(distance);
     }
+
+    /// Calculate the number of different bits between two vectors of `u8`
+    /// bytes, bailing out once the running distance exceeds `k`.
+    fn bitwise_hamming_distance_bounded(self, other: &Vec<u8>, k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Vectors do not have equal length")
+        }
+        return Ok(bytes_hamming_distance_bounded(self, other, k));
+    }
 }
 
 impl<'a, 'b> BitwiseHammingDistancable<&'a [u8]> for &'b [u8] {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the number of different bits between two slices of `u8` bytes.
     fn bitwise_hamming_distance(self, other: &[u8]) -> Result<u32, &'static str> {
         if self.len() != other.len() {
             return Err("Slices do not have equal length")
         }
-        let mut distance : u32 = 0;
-        for (b1, b2) in self.iter().zip(other.iter()) {
-            distance += b1.bitwise_hamming_distance(b2);
+        return Ok(bytes_hamming_distance(self, other));
+    }
+
+    /// Calculate the number of different bits between two slices of `u8`
+    /// bytes, bailing out once the running distance exceeds `k`.
+    fn bitwise_hamming_distance_bounded(self, other: &[u8], k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Slices do not have equal length")
         }
-        return Ok(distance);
+        return Ok(bytes_hamming_distance_bounded(self, other, k));
+    }
+}
+
+/// Calculate the hamming distance, in bits, between two equal-length byte slices.
+///
+/// Dispatches to an AVX2 path on `x86_64` targets that support it at runtime,
+/// falling back to a scalar word-at-a-time loop everywhere else.
+fn bytes_hamming_distance(a: &[u8], b: &[u8]) -> u32 {
+    debug_assert_eq!(a.len(), b.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { bytes_hamming_distance_avx2(a, b) };
+        }
+    }
+
+    bytes_hamming_distance_words(a, b)
+}
+
+/// Scalar word-at-a-time popcount loop.
+///
+/// XORs the aligned `u64` interior of both slices and sums `count_ones()` per
+/// word, handling the unaligned head and tail bytes one at a time.
+fn bytes_hamming_distance_words(a: &[u8], b: &[u8]) -> u32 {
+    let (a_head, a_words, a_tail) = unsafe { a.align_to::<u64>() };
+    let (b_head, b_words, b_tail) = unsafe { b.align_to::<u64>() };
+
+    // The two slices may not share the same aligned interior (e.g. if one
+    // starts at an odd offset relative to the other); only take the word
+    // fast path when they line up, otherwise fall back to bytes.
+    if a_head.len() != b_head.len() || a_words.len() != b_words.len() {
+        return a.iter().zip(b.iter())
+            .map(|(byte_a, byte_b)| (byte_a ^ byte_b).count_ones())
+            .sum();
+    }
+
+    let mut distance : u32 = 0;
+    for (byte_a, byte_b) in a_head.iter().zip(b_head.iter()) {
+        distance += (byte_a ^ byte_b).count_ones();
+    }
+    for (word_a, word_b) in a_words.iter().zip(b_words.iter()) {
+        distance += (word_a ^ word_b).count_ones();
+    }
+    for (byte_a, byte_b) in a_tail.iter().zip(b_tail.iter()) {
+        distance += (byte_a ^ byte_b).count_ones();
+    }
+    return distance;
+}
+
+/// Word-at-a-time popcount loop that bails out early once the running
+/// distance exceeds `k`, checking the threshold once per word over the
+/// aligned `u64` interior and once per byte over the head and tail bytes.
+///
+/// When the two slices do not share the same `u64` alignment, the word path
+/// is not used: the whole comparison is a byte loop that checks the
+/// threshold after every byte.
+pub(crate) fn bytes_hamming_distance_bounded(a: &[u8], b: &[u8], k: u32) -> Option<u32> {
+    debug_assert_eq!(a.len(), b.len());
+
+    let (a_head, a_words, a_tail) = unsafe { a.align_to::<u64>() };
+    let (b_head, b_words, b_tail) = unsafe { b.align_to::<u64>() };
+
+    if a_head.len() != b_head.len() || a_words.len() != b_words.len() {
+        let mut distance = 0u32;
+        for (byte_a, byte_b) in a.iter().zip(b.iter()) {
+            distance += (byte_a ^ byte_b).count_ones();
+            if distance > k {
+                return None;
+            }
+        }
+        return Some(distance);
+    }
+
+    let mut distance : u32 = 0;
+    for (byte_a, byte_b) in a_head.iter().zip(b_head.iter()) {
+        distance += (byte_a ^ byte_b).count_ones();
+        if distance > k {
+            return None;
+        }
+    }
+    for (word_a, word_b) in a_words.iter().zip(b_words.iter()) {
+        distance += (word_a ^ word_b).count_ones();
+        if distance > k {
+            return None;
+        }
+    }
+    for (byte_a, byte_b) in a_tail.iter().zip(b_tail.iter()) {
+        distance += (byte_a ^ byte_b).count_ones();
+        if distance > k {
+            return None;
+        }
+    }
+    return Some(distance);
+}
+
+/// AVX2 popcount path: XORs 256-bit lanes and reduces the popcount of each
+/// lane via a nibble lookup table and `_mm256_sad_epu8`, falling back to the
+/// scalar byte loop for the tail that doesn't fill a full lane.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn bytes_hamming_distance_avx2(a: &[u8], b: &[u8]) -> u32 {
+    let mut distance : u32 = 0;
+    let mut offset = 0usize;
+
+    while offset + 32 <= a.len() {
+        let va = _mm256_loadu_si256(a.as_ptr().add(offset) as *const __m256i);
+        let vb = _mm256_loadu_si256(b.as_ptr().add(offset) as *const __m256i);
+        let xor = _mm256_xor_si256(va, vb);
+        distance += popcount_avx2(xor);
+        offset += 32;
     }
+
+    for i in offset..a.len() {
+        distance += (a[i] ^ b[i]).count_ones();
+    }
+
+    return distance;
+}
+
+/// Horizontal popcount of a 256-bit lane using the standard 4-bit nibble
+/// lookup table (via `_mm256_shuffle_epi8`) followed by a `_mm256_sad_epu8`
+/// reduction down to four 64-bit partial sums.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn popcount_avx2(v: __m256i) -> u32 {
+    let low_mask = _mm256_set1_epi8(0x0f);
+    let nibble_popcounts = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+    );
+
+    let low_nibbles = _mm256_and_si256(v, low_mask);
+    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16(v, 4), low_mask);
+    let low_counts = _mm256_shuffle_epi8(nibble_popcounts, low_nibbles);
+    let high_counts = _mm256_shuffle_epi8(nibble_popcounts, high_nibbles);
+    let byte_counts = _mm256_add_epi8(low_counts, high_counts);
+
+    let sums = _mm256_sad_epu8(byte_counts, _mm256_setzero_si256());
+
+    let mut parts = [0u64; 4];
+    _mm256_storeu_si256(parts.as_mut_ptr() as *mut __m256i, sums);
+    return parts.iter().sum::<u64>() as u32;
 }
 
 #[cfg(test)]
@@ -108,4 +287,90 @@ mod tests {
         assert!(byte_slice1.bitwise_hamming_distance(byte_slice2).unwrap_err() ==
             "Slices do not have equal length");
     }
+
+    #[test]
+    fn word_and_simd_paths_agree_with_scalar() {
+        fn next_random_byte(state: &mut u64) -> u8 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            (*state & 0xff) as u8
+        }
+
+        let mut state = 0x9e3779b97f4a7c15u64;
+
+        for len in 0..96usize {
+            let a : Vec<u8> = (0..len).map(|_| next_random_byte(&mut state)).collect();
+            let b : Vec<u8> = (0..len).map(|_| next_random_byte(&mut state)).collect();
+
+            let scalar : u32 = a.iter().zip(b.iter())
+                .map(|(byte_a, byte_b)| (byte_a ^ byte_b).count_ones())
+                .sum();
+
+            assert!(a.as_slice().bitwise_hamming_distance(b.as_slice()).unwrap() == scalar);
+            assert!(super::bytes_hamming_distance_words(&a, &b) == scalar);
+
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("avx2") {
+                    assert!(unsafe { super::bytes_hamming_distance_avx2(&a, &b) } == scalar);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn u8_bitwise_hamming_distance_bounded() {
+        let byte1 : u8 = 0x01;
+        let byte2 : u8 = 0xFF;
+
+        assert!(byte1.bitwise_hamming_distance_bounded(&byte2, 7) == Some(7));
+        assert!(byte1.bitwise_hamming_distance_bounded(&byte2, 6) == None);
+    }
+
+    #[test]
+    fn u8_slice_bitwise_hamming_distance_bounded() {
+        let byte_slice1 : &[u8] = &[0x01, 0x01, 0x01];
+        let byte_slice2 : &[u8] = &[0xFF, 0xFF, 0x01];
+
+        assert!(byte_slice1.bitwise_hamming_distance_bounded(byte_slice2, 14).unwrap() == Some(14));
+        assert!(byte_slice1.bitwise_hamming_distance_bounded(byte_slice2, 13).unwrap() == None);
+    }
+
+    #[test]
+    fn u8_slice_bitwise_hamming_distance_bounded_error() {
+        let byte_slice1 : &[u8] = &[0x01];
+        let byte_slice2 : &[u8] = &[0x01, 0xFF];
+
+        assert!(byte_slice1.bitwise_hamming_distance_bounded(byte_slice2, 1).unwrap_err() ==
+            "Slices do not have equal length");
+    }
+
+    #[test]
+    fn bounded_path_agrees_with_unbounded() {
+        fn next_random_byte(state: &mut u64) -> u8 {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            (*state & 0xff) as u8
+        }
+
+        let mut state = 0x243f6a8885a308d3u64;
+
+        for len in 0..96usize {
+            let a : Vec<u8> = (0..len).map(|_| next_random_byte(&mut state)).collect();
+            let b : Vec<u8> = (0..len).map(|_| next_random_byte(&mut state)).collect();
+
+            let full = a.as_slice().bitwise_hamming_distance(b.as_slice()).unwrap();
+
+            for k in 0..(full + 2) {
+                let bounded = a.as_slice().bitwise_hamming_distance_bounded(b.as_slice(), k).unwrap();
+                if full <= k {
+                    assert!(bounded == Some(full));
+                } else {
+                    assert!(bounded == None);
+                }
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -2,12 +2,18 @@
 pub trait HammingDistancable<RHS = Self> {
     /// The output type of the hamming distance
     type Output;
+    /// The output type of the bounded hamming distance
+    type BoundedOutput;
     fn hamming_distance(self, other: RHS) -> Self::Output;
+    /// Calculate the hamming distance, bailing out early once it exceeds `k`
+    /// differing elements, rather than comparing the rest.
+    fn hamming_distance_bounded(self, other: RHS, k: u32) -> Self::BoundedOutput;
 }
 
 impl<'a, 'b, T> HammingDistancable<&'b Vec<T>> for &'a Vec<T>
     where T : Eq {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the hamming distance between vectors
     fn hamming_distance(self, other: &'b Vec<T>) -> Result<u32, &'static str> {
         if self.len() != other.len() {
@@ -23,11 +29,22 @@ impl<'a, 'b, T> HammingDistancable<&'b Vec<T>> for &'a Vec<T>
 
         return Ok(distance);
     }
+
+    /// Calculate the hamming distance between vectors, bailing out once the
+    /// running distance exceeds `k`.
+    fn hamming_distance_bounded(self, other: &'b Vec<T>, k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Vectors do not have equal length");
+        }
+
+        return Ok(elements_hamming_distance_bounded(self.iter(), other.iter(), k));
+    }
 }
 
 impl<'a, 'b, T> HammingDistancable<&'b [T]> for &'a [T]
     where T : Eq {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the hamming distance between slices
     fn hamming_distance(self, other: &'b [T]) -> Result<u32, &'static str> {
         if self.len() != other.len() {
@@ -43,10 +60,21 @@ impl<'a, 'b, T> HammingDistancable<&'b [T]> for &'a [T]
 
         return Ok(distance);
     }
+
+    /// Calculate the hamming distance between slices, bailing out once the
+    /// running distance exceeds `k`.
+    fn hamming_distance_bounded(self, other: &'b [T], k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Slices do not have equal length");
+        }
+
+        return Ok(elements_hamming_distance_bounded(self.iter(), other.iter(), k));
+    }
 }
 
 impl <'a, 'b> HammingDistancable<&'b String> for &'a String {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the hamming distance between strings
      fn hamming_distance(self, other: &'b String) -> Result<u32, &'static str> {
         if self.len() != other.len() {
@@ -62,10 +90,21 @@ impl <'a, 'b> HammingDistancable<&'b String> for &'a String {
 
         return Ok(distance);
     }
+
+    /// Calculate the hamming distance between strings, bailing out once the
+    /// running distance exceeds `k`.
+    fn hamming_distance_bounded(self, other: &'b String, k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Strings do not have equal length");
+        }
+
+        return Ok(elements_hamming_distance_bounded(self.chars(), other.chars(), k));
+    }
 }
 
 impl <'a, 'b> HammingDistancable<&'b str> for &'a str {
     type Output = Result<u32, &'static str>;
+    type BoundedOutput = Result<Option<u32>, &'static str>;
     /// Calculate the hamming distance between borrowed strings
      fn hamming_distance(self, other: &'b str) -> Result<u32, &'static str> {
         if self.len() != other.len() {
@@ -81,11 +120,87 @@ impl <'a, 'b> HammingDistancable<&'b str> for &'a str {
 
         return Ok(distance);
     }
+
+    /// Calculate the hamming distance between borrowed strings, bailing out
+    /// once the running distance exceeds `k`.
+    fn hamming_distance_bounded(self, other: &'b str, k: u32) -> Result<Option<u32>, &'static str> {
+        if self.len() != other.len() {
+            return Err("Strings do not have equal length");
+        }
+
+        return Ok(elements_hamming_distance_bounded(self.chars(), other.chars(), k));
+    }
+}
+
+/// Count mismatches between two equal-length iterators, bailing out to
+/// `None` as soon as the running count exceeds `k` rather than finishing
+/// the comparison.
+pub(crate) fn elements_hamming_distance_bounded<T, IA, IB>(a: IA, b: IB, k: u32) -> Option<u32>
+    where T : Eq, IA : Iterator<Item = T>, IB : Iterator<Item = T> {
+    let mut distance = 0u32;
+    for (elem_a, elem_b) in a.zip(b) {
+        if elem_a != elem_b {
+            distance += 1;
+            if distance > k {
+                return None;
+            }
+        }
+    }
+    return Some(distance);
+}
+
+/// The result of comparing two iterators element-by-element without
+/// requiring them to be the same length up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IterHammingDistance {
+    /// The number of mismatches over the common prefix of both iterators
+    pub distance: u32,
+    /// How many elements were left over in `a` once `b` was exhausted
+    pub extra_a: usize,
+    /// How many elements were left over in `b` once `a` was exhausted
+    pub extra_b: usize,
+}
+
+/// Calculate the hamming distance between two iterators, consuming them
+/// lazily instead of requiring a pre-collected `Vec`/slice/`String` of equal
+/// length.
+///
+/// Unlike `HammingDistancable`, unequal lengths are not an error: `extra_a`
+/// and `extra_b` report how many elements were left over on whichever side
+/// ran longer, so callers can decide whether the trailing elements should
+/// count as mismatches or be ignored, rather than having to pre-check
+/// lengths to compare just the common prefix.
+pub fn hamming_distance_iter<T, A, B>(a: A, b: B) -> IterHammingDistance
+    where T : Eq, A : IntoIterator<Item = T>, B : IntoIterator<Item = T> {
+    let mut iter_a = a.into_iter();
+    let mut iter_b = b.into_iter();
+    let mut distance = 0u32;
+
+    loop {
+        match (iter_a.next(), iter_b.next()) {
+            (Some(elem_a), Some(elem_b)) => {
+                if elem_a != elem_b {
+                    distance += 1;
+                }
+            }
+            (Some(_), None) => {
+                let extra_a = 1 + iter_a.count();
+                return IterHammingDistance { distance: distance, extra_a: extra_a, extra_b: 0 };
+            }
+            (None, Some(_)) => {
+                let extra_b = 1 + iter_b.count();
+                return IterHammingDistance { distance: distance, extra_a: 0, extra_b: extra_b };
+            }
+            (None, None) => {
+                return IterHammingDistance { distance: distance, extra_a: 0, extra_b: 0 };
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use hamming_distance::HammingDistancable;
+    use hamming_distance::{HammingDistancable, IterHammingDistance, hamming_distance_iter};
     
     #[test]
     fn vec_hamming_distance() {
@@ -162,7 +277,77 @@ mod tests {
         let string1 : &str = "Cats";
         let string2 : &str = "Hat";
 
-        assert!(string1.hamming_distance(string2).unwrap_err() == 
+        assert!(string1.hamming_distance(string2).unwrap_err() ==
             "Strings do not have equal length");
     }
+
+    #[test]
+    fn vec_hamming_distance_bounded() {
+        let vec1 : Vec<char> = vec!['a', 'b', 'c'];
+        let vec2 : Vec<char> = vec!['x', 'y', 'c'];
+
+        assert!(vec1.hamming_distance_bounded(&vec2, 2).unwrap() == Some(2));
+        assert!(vec1.hamming_distance_bounded(&vec2, 1).unwrap() == None);
+    }
+
+    #[test]
+    fn vec_hamming_distance_bounded_error() {
+        let vec1 : Vec<char> = vec!['a', 'b'];
+        let vec2 : Vec<char> = vec!['a'];
+
+        assert!(vec1.hamming_distance_bounded(&vec2, 1).unwrap_err() ==
+            "Vectors do not have equal length");
+    }
+
+    #[test]
+    fn string_hamming_distance_bounded() {
+        let string1 : String = "Cats".to_owned();
+        let string2 : String = "Cart".to_owned();
+
+        assert!(string1.hamming_distance_bounded(&string2, 2).unwrap() == Some(2));
+        assert!(string1.hamming_distance_bounded(&string2, 1).unwrap() == None);
+    }
+
+    #[test]
+    fn borrowed_string_hamming_distance_bounded() {
+        let string1 : &str = "Cats";
+        let string2 : &str = "Cart";
+
+        assert!(string1.hamming_distance_bounded(string2, 2).unwrap() == Some(2));
+        assert!(string1.hamming_distance_bounded(string2, 1).unwrap() == None);
+    }
+
+    #[test]
+    fn iter_hamming_distance_equal_length() {
+        let result = hamming_distance_iter("Cat".chars(), "Hat".chars());
+
+        assert!(result == IterHammingDistance { distance: 1, extra_a: 0, extra_b: 0 });
+    }
+
+    #[test]
+    fn iter_hamming_distance_extra_a() {
+        let result = hamming_distance_iter("Cats".chars(), "Hat".chars());
+
+        assert!(result == IterHammingDistance { distance: 1, extra_a: 1, extra_b: 0 });
+    }
+
+    #[test]
+    fn iter_hamming_distance_extra_b() {
+        let result = hamming_distance_iter("Cat".chars(), "Hats".chars());
+
+        assert!(result == IterHammingDistance { distance: 1, extra_a: 0, extra_b: 1 });
+    }
+
+    #[test]
+    fn iter_hamming_distance_over_filtered_iterators() {
+        let a = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let b = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        let result = hamming_distance_iter(
+            a.iter().filter(|&&x| x % 2 == 0),
+            b.iter().filter(|&&x| x % 2 == 0),
+        );
+
+        assert!(result == IterHammingDistance { distance: 0, extra_a: 0, extra_b: 1 });
+    }
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+//! Searching a haystack for windows that approximately match a needle
+
+use bitwise_hamming_distance::bytes_hamming_distance_bounded;
+use hamming_distance::elements_hamming_distance_bounded;
+
+/// A window of the haystack within the requested hamming distance of the needle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The offset into the haystack where the matching window starts
+    pub start: usize,
+    /// The hamming distance between the needle and this window
+    pub distance: u32,
+}
+
+/// Find every window of `haystack` whose hamming distance to `needle` is at
+/// most `k`, specialized for byte slices so it reuses the bounded
+/// word-at-a-time popcount loop. The AVX2 path is not used here.
+///
+/// An empty `needle` matches at every position with distance `0`. A `needle`
+/// longer than `haystack` yields no matches.
+pub fn hamming_search_bytes(needle: &[u8], haystack: &[u8], k: u32) -> Vec<Match> {
+    if needle.is_empty() {
+        return (0..=haystack.len()).map(|start| Match { start: start, distance: 0 }).collect();
+    }
+    if needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    for start in 0..=(haystack.len() - needle.len()) {
+        let window = &haystack[start..start + needle.len()];
+        if let Some(distance) = bytes_hamming_distance_bounded(needle, window, k) {
+            matches.push(Match { start: start, distance: distance });
+        }
+    }
+    return matches;
+}
+
+/// Find every window of `haystack` whose hamming distance to `needle` is at
+/// most `k`, generic over any `T: Eq`.
+///
+/// An empty `needle` matches at every position with distance `0`. A `needle`
+/// longer than `haystack` yields no matches.
+pub fn hamming_search<T>(needle: &[T], haystack: &[T], k: u32) -> Vec<Match>
+    where T : Eq {
+    if needle.is_empty() {
+        return (0..=haystack.len()).map(|start| Match { start: start, distance: 0 }).collect();
+    }
+    if needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    for start in 0..=(haystack.len() - needle.len()) {
+        let window = &haystack[start..start + needle.len()];
+        if let Some(distance) = elements_hamming_distance_bounded(needle.iter(), window.iter(), k) {
+            matches.push(Match { start: start, distance: distance });
+        }
+    }
+    return matches;
+}
+
+#[cfg(test)]
+mod tests {
+    use hamming_search::{hamming_search, hamming_search_bytes, Match};
+
+    #[test]
+    fn finds_exact_and_near_matches_in_bytes() {
+        let needle : &[u8] = &[0x01, 0x02];
+        let haystack : &[u8] = &[0xFF, 0x01, 0x02, 0xFF, 0x01, 0x03];
+
+        let matches = hamming_search_bytes(needle, haystack, 1);
+
+        assert!(matches == vec![
+            Match { start: 1, distance: 0 },
+            Match { start: 4, distance: 1 },
+        ]);
+    }
+
+    #[test]
+    fn excludes_windows_past_the_threshold() {
+        let needle : &[u8] = &[0x00, 0x00];
+        let haystack : &[u8] = &[0xFF, 0xFF, 0x00, 0x00];
+
+        let matches = hamming_search_bytes(needle, haystack, 0);
+
+        assert!(matches == vec![Match { start: 2, distance: 0 }]);
+    }
+
+    #[test]
+    fn empty_needle_matches_every_position() {
+        let needle : &[u8] = &[];
+        let haystack : &[u8] = &[0x01, 0x02, 0x03];
+
+        let matches = hamming_search_bytes(needle, haystack, 0);
+
+        assert!(matches == vec![
+            Match { start: 0, distance: 0 },
+            Match { start: 1, distance: 0 },
+            Match { start: 2, distance: 0 },
+            Match { start: 3, distance: 0 },
+        ]);
+    }
+
+    #[test]
+    fn needle_longer_than_haystack_has_no_matches() {
+        let needle : &[u8] = &[0x01, 0x02, 0x03];
+        let haystack : &[u8] = &[0x01, 0x02];
+
+        assert!(hamming_search_bytes(needle, haystack, 3) == Vec::new());
+    }
+
+    #[test]
+    fn generic_search_over_chars() {
+        let needle : Vec<char> = "ab".chars().collect();
+        let haystack : Vec<char> = "xaybabz".chars().collect();
+
+        let matches = hamming_search(&needle, &haystack, 1);
+
+        assert!(matches == vec![
+            Match { start: 1, distance: 1 },
+            Match { start: 2, distance: 1 },
+            Match { start: 4, distance: 0 },
+        ]);
+    }
+}